@@ -1,13 +1,22 @@
 use crate::prelude::*;
 
-/// Hide content without affecting layout.
+/// Hide content, optionally removing it from the flow entirely.
 #[derive(Debug, Hash)]
-pub struct HideNode(pub Content);
+pub struct HideNode {
+    /// The content to hide.
+    pub body: Content,
+    /// Whether the content still reserves its layout space. If `false`, the
+    /// node behaves as if it were out-of-flow: it contributes a zero-size
+    /// frame and surrounding content flows as if it were absent.
+    pub reserve: bool,
+}
 
 #[node(LayoutInline)]
 impl HideNode {
     fn construct(_: &mut Vm, args: &mut Args) -> SourceResult<Content> {
-        Ok(Self(args.expect("body")?).pack())
+        let body = args.expect("body")?;
+        let reserve = args.named("reserve")?.unwrap_or(true);
+        Ok(Self { body, reserve }.pack())
     }
 }
 
@@ -18,8 +27,693 @@ impl LayoutInline for HideNode {
         regions: &Regions,
         styles: StyleChain,
     ) -> SourceResult<Frame> {
-        let mut frame = self.0.layout_inline(world, regions, styles)?;
+        let mut frame = self.body.layout_inline(world, regions, styles)?;
         frame.clear();
+
+        if !self.reserve {
+            frame.resize(Size::zero());
+        }
+
         Ok(frame)
     }
-}
\ No newline at end of file
+}
+
+/// # Clip
+/// Clip content to a rectangular region instead of letting it overflow.
+/// Elements that lie wholly inside the region are kept; an element that
+/// extends past the region is dropped whole, including its part inside the
+/// region. Elements are not cut at the boundary.
+///
+/// ## Example
+/// ```
+/// #clip(size: (50pt, 50pt))[
+///   #rect(width: 100pt, height: 100pt, fill: red)
+/// ]
+/// ```
+///
+/// ## Parameters
+/// - body: Content (positional, required)
+///   The content to clip.
+///
+/// - size: Axes<Rel<Length>> (named)
+///   The size of the clip region, anchored at the content's origin. Defaults
+///   to the size the content would otherwise occupy.
+///
+/// ## Category
+/// graphics
+#[func]
+#[capable(LayoutInline)]
+#[derive(Debug, Hash)]
+pub struct ClipNode {
+    /// The content to clip.
+    pub body: Content,
+    /// The size of the clip region, if different from the content's own
+    /// size.
+    pub size: Option<Axes<Rel<Length>>>,
+}
+
+#[node(LayoutInline)]
+impl ClipNode {
+    fn construct(_: &mut Vm, args: &mut Args) -> SourceResult<Content> {
+        let body = args.expect("body")?;
+        let size = args.named("size")?;
+        Ok(Self { body, size }.pack())
+    }
+}
+
+impl LayoutInline for ClipNode {
+    fn layout_inline(
+        &self,
+        world: Tracked<dyn World>,
+        regions: &Regions,
+        styles: StyleChain,
+    ) -> SourceResult<Frame> {
+        let frame = self.body.layout_inline(world, regions, styles)?;
+
+        let clip_size = match &self.size {
+            Some(size) => size
+                .resolve(styles)
+                .zip(regions.base)
+                .map(|(l, b)| l.relative_to(b)),
+            None => frame.size(),
+        };
+
+        Ok(clip_frame(frame, clip_size))
+    }
+}
+
+/// Mask everything in `frame` outside the rectangle from the origin to
+/// `clip_size`. The result is sized to `clip_size`, not to the original
+/// frame's size.
+///
+/// Elements that cross the boundary are dropped wholesale rather than
+/// geometrically clipped, since cutting a single element's geometry at the
+/// boundary requires support from the exporter that produced it. An element
+/// is kept only if its full extent (not just its origin) fits inside the
+/// clip rectangle.
+fn clip_frame(frame: Frame, clip_size: Size) -> Frame {
+    let mut clipped = Frame::new(clip_size);
+
+    for (pos, element) in frame.elements() {
+        let extent = element_extent(element);
+        let inside = pos.x >= Abs::zero()
+            && pos.y >= Abs::zero()
+            && pos.x + extent.x <= clip_size.x
+            && pos.y + extent.y <= clip_size.y;
+
+        if inside {
+            clipped.push(*pos, element.clone());
+        }
+    }
+
+    clipped
+}
+
+/// The space `element` occupies, used to decide whether it fits inside a
+/// clip rectangle.
+///
+/// Only groups, text and line or rectangle shapes get a derived extent.
+/// Every other element, including ellipse and path shapes, gets
+/// `Size::zero()`: it looks like it always fits, so it's always kept no
+/// matter how far it actually extends past `clip_size`.
+fn element_extent(element: &Element) -> Size {
+    match element {
+        Element::Shape(shape) => geometry_extent(&shape.geometry),
+        Element::Group(group) => group.frame.size(),
+        Element::Text(text) => {
+            let width = text
+                .glyphs
+                .iter()
+                .map(|g| g.x_advance.at(text.size))
+                .fold(Abs::zero(), |a, b| a + b);
+            Size::new(width, text.size)
+        }
+        _ => Size::zero(),
+    }
+}
+
+/// The bounding box size of a line or rectangle. Other geometry yields
+/// `Size::zero()`.
+fn geometry_extent(geometry: &Geometry) -> Size {
+    match geometry {
+        Geometry::Line(to) => Size::new(to.x.abs(), to.y.abs()),
+        Geometry::Rect(size) => *size,
+        _ => Size::zero(),
+    }
+}
+
+/// # Truncate
+/// Clip inline content to a maximum number of lines or inline size, appending
+/// an ellipsis instead of letting it overflow.
+///
+/// ## Example
+/// ```
+/// #truncate(lines: 1)[
+///   This sentence is much too long to fit on a single line.
+/// ]
+/// ```
+///
+/// ## Parameters
+/// - body: Content (positional, required)
+///   The content to truncate.
+///
+/// - lines: usize (named)
+///   The maximum number of lines to keep. Applies together with
+///   `max-inline-size` in both modes (see `per-line`).
+///
+/// - max-inline-size: Rel<Length> (named)
+///   The maximum inline size a (or the) line may occupy before truncation
+///   kicks in. Defaults to the available inline size of the region.
+///
+/// - ellipsis: EcoString (named)
+///   The string appended in place of the clipped content.
+///
+/// - per-line: bool (named)
+///   Whether truncation is applied independently to each line (`true`) or
+///   only once to the content as a whole (`false`).
+///
+/// ## Category
+/// graphics
+#[func]
+#[capable(LayoutInline)]
+#[derive(Debug, Hash)]
+pub struct TruncateNode {
+    /// The content to truncate.
+    pub body: Content,
+    /// The maximum number of lines to keep, if any.
+    pub lines: Option<usize>,
+    /// The maximum inline size a line may occupy, if different from the
+    /// available inline size of the region.
+    pub max_inline_size: Option<Rel<Length>>,
+}
+
+#[node(LayoutInline)]
+impl TruncateNode {
+    /// The string appended in place of clipped content.
+    #[property(referenced)]
+    pub const ELLIPSIS: EcoString = EcoString::from("…");
+
+    /// Whether truncation applies per line or to the block as a whole.
+    #[property(copy)]
+    pub const PER_LINE: bool = false;
+
+    fn construct(_: &mut Vm, args: &mut Args) -> SourceResult<Content> {
+        let body = args.expect("body")?;
+        let lines = args.named("lines")?;
+        let max_inline_size = args.named("max-inline-size")?;
+        Ok(Self { body, lines, max_inline_size }.pack())
+    }
+}
+
+impl LayoutInline for TruncateNode {
+    fn layout_inline(
+        &self,
+        world: Tracked<dyn World>,
+        regions: &Regions,
+        styles: StyleChain,
+    ) -> SourceResult<Frame> {
+        let ellipsis = styles.get(Self::ELLIPSIS);
+        let per_line = styles.get(Self::PER_LINE);
+
+        let frame = self.body.layout_inline(world, regions, styles)?;
+        let max_inline_size = match self.max_inline_size {
+            Some(rel) => rel.resolve(styles).relative_to(regions.base.x),
+            None => regions.first.x,
+        };
+
+        let runs = TextRun::from_frame(&frame);
+
+        // Nothing needs truncating: hand back the original frame untouched
+        // rather than re-synthesizing it from the extracted runs, so content
+        // the breaking strategy can't yet reconstruct perfectly (e.g. glyphs
+        // outside any detected run) is never silently lost.
+        let max_lines = self.lines.unwrap_or(usize::MAX);
+        let needs_truncation = runs
+            .iter()
+            .enumerate()
+            .any(|(i, run)| i >= max_lines || run.width() > max_inline_size);
+        if !needs_truncation {
+            return Ok(frame);
+        }
+
+        Ok(truncate_runs(&runs, frame.size(), max_lines, max_inline_size, per_line, &ellipsis))
+    }
+}
+
+/// Truncate a frame's extracted runs down to `max_lines` lines of at most
+/// `max_inline_size` each. Assumes the caller has already established that
+/// at least one run needs cutting.
+///
+/// In per-line mode, every run that overflows `max_inline_size` is truncated
+/// independently and the rest are kept verbatim. In block mode, runs are
+/// kept verbatim up to the last one we're allowed to keep (bounded by
+/// `max_lines` and by `max_inline_size`); that last kept run alone is
+/// truncated, so its ellipsis is the one visual signal that content
+/// followed it, and any runs beyond it are dropped.
+fn truncate_runs(
+    runs: &[TextRun],
+    frame_size: Size,
+    max_lines: usize,
+    max_inline_size: Abs,
+    per_line: bool,
+    ellipsis: &EcoString,
+) -> Frame {
+    let mut truncated = Frame::new(frame_size);
+    for (i, run) in runs.iter().enumerate() {
+        if i >= max_lines {
+            break;
+        }
+
+        if per_line {
+            // Each line is judged and truncated on its own merits, so keep
+            // iterating regardless of what happened to earlier ones.
+            if run.width() <= max_inline_size {
+                truncated.push_frame(run.pos, run.frame.clone());
+            } else {
+                let result = truncate_run(run, max_inline_size, ellipsis);
+                truncated.push_frame(run.pos, result.into_frame(run, max_inline_size));
+            }
+            continue;
+        }
+
+        let is_last_kept = i + 1 >= max_lines || i + 1 >= runs.len();
+        let overflows = run.width() > max_inline_size;
+        let more_follows = i + 1 < runs.len();
+
+        if !overflows && (!is_last_kept || !more_follows) {
+            truncated.push_frame(run.pos, run.frame.clone());
+            if is_last_kept {
+                break;
+            }
+            continue;
+        }
+
+        let result = truncate_run(run, max_inline_size, ellipsis);
+        truncated.push_frame(run.pos, result.into_frame(run, max_inline_size));
+        break;
+    }
+
+    truncated
+}
+
+/// A single laid-out line, reduced to the glyph/cluster slices needed to
+/// decide where to cut it off.
+struct TextRun {
+    /// The run's position within its parent frame.
+    pos: Point,
+    /// The original, untruncated frame for this line (a single text element
+    /// or a group of them), kept around so a run that turns out to fit can be
+    /// reused verbatim.
+    frame: Frame,
+    /// Clusters in visual order, each with its advance width and whether it
+    /// sits on a legal line-break opportunity.
+    clusters: Vec<Cluster>,
+}
+
+/// One glyph of a shaped text run. A grapheme cluster shaped into several
+/// glyphs yields several entries, so a cut can fall inside it.
+#[derive(Clone)]
+struct Cluster {
+    /// The advance width of this cluster.
+    width: Abs,
+    /// Whether a line may legally break right after this cluster.
+    breakable: bool,
+}
+
+impl TextRun {
+    /// The run's total advance width.
+    fn width(&self) -> Abs {
+        self.clusters.iter().map(|c| c.width).fold(Abs::zero(), |a, b| a + b)
+    }
+
+    /// Collect the text runs that make up a frame, in visual order. Each
+    /// top-level [`Element::Group`] (one laid-out line in a multi-line
+    /// paragraph) becomes its own run; a frame holding bare
+    /// [`Element::Text`] items directly (a single line) becomes one run for
+    /// the whole frame.
+    fn from_frame(frame: &Frame) -> Vec<Self> {
+        let mut runs = vec![];
+
+        if frame.elements().iter().any(|(_, el)| matches!(el, Element::Group(_))) {
+            for (pos, element) in frame.elements() {
+                if let Element::Group(group) = element {
+                    if let Some(run) = Self::from_line(*pos, group.frame.clone()) {
+                        runs.push(run);
+                    }
+                }
+            }
+        } else if let Some(run) = Self::from_line(Point::zero(), frame.clone()) {
+            runs.push(run);
+        }
+
+        runs
+    }
+
+    /// Build a single run from a line's own frame.
+    fn from_line(pos: Point, frame: Frame) -> Option<Self> {
+        let mut clusters = vec![];
+
+        for (_, element) in frame.elements() {
+            if let Element::Text(text) = element {
+                for glyph in &text.glyphs {
+                    let width = glyph.x_advance.at(text.size);
+                    let start = glyph.range.start as usize;
+                    let end = glyph.range.end as usize;
+                    let slice = text.text.get(start..end).unwrap_or_default();
+                    let breakable =
+                        !slice.is_empty() && slice.chars().all(|c| c.is_whitespace());
+                    clusters.push(Cluster { width, breakable });
+                }
+            }
+        }
+
+        if clusters.is_empty() {
+            return None;
+        }
+
+        Some(Self { pos, frame, clusters })
+    }
+}
+
+/// The result of truncating a single run.
+struct Truncation {
+    /// How many leading clusters of the run's text survive, and their
+    /// combined advance width.
+    prefix: usize,
+    width: Abs,
+    /// The ellipsis to append after the surviving prefix.
+    ellipsis: EcoString,
+}
+
+impl Truncation {
+    /// Render the surviving prefix of `run`'s original frame plus a
+    /// synthesized ellipsis, all within `max_inline_size`.
+    ///
+    /// `run.clusters` is the concatenation of every [`Element::Text`] item's
+    /// glyphs in `run.frame`, in order, so `self.prefix` (a cluster count)
+    /// may fall in the middle of one of those items. Clipping element-wise
+    /// (as [`clip_frame`] does) can't express that: the item's full extent
+    /// wouldn't fit, so the whole item would be dropped, not just its
+    /// overflowing glyphs. Instead, walk the items in order, copying whole
+    /// ones that fit entirely within the prefix and slicing the one that
+    /// straddles the cut to its surviving glyphs.
+    fn into_frame(self, run: &TextRun, max_inline_size: Abs) -> Frame {
+        let mut frame = Frame::new(Size::new(max_inline_size, run.frame.size().y));
+        let mut consumed = 0;
+
+        for (pos, element) in run.frame.elements() {
+            if consumed >= self.prefix {
+                break;
+            }
+
+            let Element::Text(text) = element else {
+                frame.push(*pos, element.clone());
+                continue;
+            };
+
+            let remaining = self.prefix - consumed;
+            if remaining >= text.glyphs.len() {
+                consumed += text.glyphs.len();
+                frame.push(*pos, Element::Text(text.clone()));
+                continue;
+            }
+
+            let (glyphs, end) = slice_glyphs(&text.glyphs, remaining);
+            let slice = text.text.get(..end).unwrap_or_default();
+            frame.push(
+                *pos,
+                Element::Text(TextItem {
+                    font: text.font.clone(),
+                    size: text.size,
+                    fill: text.fill,
+                    lang: text.lang,
+                    text: slice.into(),
+                    glyphs,
+                }),
+            );
+            consumed += remaining;
+        }
+
+        if self.prefix < run.clusters.len() {
+            if let Some((_, Element::Text(last))) = run
+                .frame
+                .elements()
+                .iter()
+                .rev()
+                .find(|(_, el)| matches!(el, Element::Text(_)))
+            {
+                let ellipsis_item = synthesize_ellipsis(last, &self.ellipsis);
+                frame.push(
+                    Point::new(self.width, Abs::zero()),
+                    Element::Text(ellipsis_item),
+                );
+            }
+        }
+
+        frame
+    }
+}
+
+/// Slice `glyphs` down to its first `count` entries (clamped to however many
+/// there are), returning them alongside the byte offset into the backing
+/// string that the last surviving glyph ends at, so the string can be sliced
+/// to match.
+fn slice_glyphs(glyphs: &[Glyph], count: usize) -> (Vec<Glyph>, usize) {
+    let glyphs = glyphs[..count.min(glyphs.len())].to_vec();
+    let end = glyphs.last().map(|g| g.range.end as usize).unwrap_or(0);
+    (glyphs, end)
+}
+
+/// Build a placeholder [`TextItem`] for the ellipsis, reusing the font,
+/// size, fill and language of `like`. The glyph geometry is an advance-width
+/// estimate rather than a real shaping result, since this module has no
+/// access to the shaping engine.
+fn synthesize_ellipsis(like: &TextItem, ellipsis: &EcoString) -> TextItem {
+    let per_char = measure_ellipsis(ellipsis) / ellipsis.chars().count().max(1) as f64;
+    let glyphs = ellipsis
+        .char_indices()
+        .map(|(i, c)| Glyph {
+            id: 0,
+            x_advance: Em::from_length(per_char, like.size),
+            x_offset: Em::zero(),
+            range: i as u16..(i + c.len_utf8()) as u16,
+        })
+        .collect();
+
+    TextItem {
+        font: like.font.clone(),
+        size: like.size,
+        fill: like.fill,
+        lang: like.lang,
+        text: ellipsis.clone(),
+        glyphs,
+    }
+}
+
+/// Truncate `run` to fit within `max_inline_size`, appending `ellipsis`.
+///
+/// Tries the word-level strategy first: clusters are only cut at a legal
+/// line-break opportunity, so whole words survive intact. If not even one
+/// word fits, falls back to the glyph-level strategy, which may cut mid-word
+/// at any glyph boundary (see [`Cluster`]). If nothing at
+/// all fits, only the ellipsis is emitted.
+fn truncate_run(run: &TextRun, max_inline_size: Abs, ellipsis: &EcoString) -> Truncation {
+    let ellipsis_width = measure_ellipsis(ellipsis);
+    let budget = max_inline_size - ellipsis_width;
+
+    if let Some((prefix, width)) = break_at(run, budget, true) {
+        return Truncation { prefix, width, ellipsis: ellipsis.clone() };
+    }
+
+    if let Some((prefix, width)) = break_at(run, budget, false) {
+        return Truncation { prefix, width, ellipsis: ellipsis.clone() };
+    }
+
+    Truncation { prefix: 0, width: Abs::zero(), ellipsis: ellipsis.clone() }
+}
+
+/// Walk `run`'s clusters, accumulating advance widths until the next cluster
+/// would exceed `budget`. When `word_level` is set, only accepts boundaries
+/// that fall on a legal line-break opportunity (never inside a word);
+/// otherwise accepts any boundary between entries of `run.clusters`, which
+/// are single glyphs (see [`Cluster`]).
+/// Returns the number of surviving clusters and their combined width.
+fn break_at(run: &TextRun, budget: Abs, word_level: bool) -> Option<(usize, Abs)> {
+    let mut width = Abs::zero();
+    let mut last_fit = None;
+
+    for (i, cluster) in run.clusters.iter().enumerate() {
+        if width + cluster.width > budget {
+            break;
+        }
+
+        width += cluster.width;
+
+        if !word_level || cluster.breakable {
+            last_fit = Some((i + 1, width));
+        }
+    }
+
+    last_fit
+}
+
+/// Measure the advance width of the synthesized ellipsis glyph(s).
+fn measure_ellipsis(ellipsis: &EcoString) -> Abs {
+    // Proportional to the number of characters until real glyph metrics are
+    // available to this module.
+    Abs::pt(ellipsis.chars().count() as f64 * 6.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster(width: f64, breakable: bool) -> Cluster {
+        Cluster { width: Abs::pt(width), breakable }
+    }
+
+    fn run(clusters: Vec<Cluster>) -> TextRun {
+        TextRun { pos: Point::zero(), frame: Frame::new(Size::zero()), clusters }
+    }
+
+    #[test]
+    fn test_break_at_word_level_stops_at_last_space() {
+        // "ab cd ef", each letter 5pt wide, each space 2pt wide.
+        let run = run(vec![
+            cluster(5.0, false),
+            cluster(5.0, false),
+            cluster(2.0, true),
+            cluster(5.0, false),
+            cluster(5.0, false),
+            cluster(2.0, true),
+            cluster(5.0, false),
+            cluster(5.0, false),
+        ]);
+
+        // Budget fits "ab " (12pt) but not "ab cd " (24pt).
+        assert_eq!(break_at(&run, Abs::pt(15.0), true), Some((3, Abs::pt(12.0))));
+    }
+
+    #[test]
+    fn test_break_at_word_level_none_if_no_word_fits() {
+        let run = run(vec![cluster(5.0, false), cluster(5.0, false), cluster(2.0, true)]);
+        assert_eq!(break_at(&run, Abs::pt(4.0), true), None);
+    }
+
+    #[test]
+    fn test_break_at_cluster_level_fallback() {
+        let run = run(vec![cluster(5.0, false), cluster(5.0, false), cluster(5.0, false)]);
+        assert_eq!(break_at(&run, Abs::pt(12.0), false), Some((2, Abs::pt(10.0))));
+    }
+
+    #[test]
+    fn test_truncate_run_falls_back_to_ellipsis_only() {
+        let ellipsis = EcoString::from("...");
+        let run = run(vec![cluster(100.0, false)]);
+        let result = truncate_run(&run, Abs::pt(1.0), &ellipsis);
+        assert_eq!(result.prefix, 0);
+        assert_eq!(result.width, Abs::zero());
+    }
+
+    fn glyph(range: std::ops::Range<u16>) -> Glyph {
+        Glyph { id: 0, x_advance: Em::zero(), x_offset: Em::zero(), range }
+    }
+
+    #[test]
+    fn test_slice_glyphs_keeps_only_the_surviving_prefix() {
+        // "abc", one byte per glyph: a single Text element's glyphs must be
+        // sliced down to the surviving count, not kept or dropped wholesale.
+        let glyphs = vec![glyph(0..1), glyph(1..2), glyph(2..3)];
+        let (sliced, end) = slice_glyphs(&glyphs, 2);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(end, 2);
+    }
+
+    #[test]
+    fn test_slice_glyphs_clamps_to_the_available_glyph_count() {
+        let glyphs = vec![glyph(0..1), glyph(1..2)];
+        let (sliced, end) = slice_glyphs(&glyphs, 10);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(end, 2);
+    }
+
+    #[test]
+    fn test_slice_glyphs_zero_count_is_empty() {
+        let glyphs = vec![glyph(0..1), glyph(1..2)];
+        let (sliced, end) = slice_glyphs(&glyphs, 0);
+        assert!(sliced.is_empty());
+        assert_eq!(end, 0);
+    }
+
+    #[test]
+    fn test_truncate_runs_block_mode_drops_lines_beyond_the_budget() {
+        let ellipsis = EcoString::from("...");
+        let runs = vec![
+            run(vec![cluster(5.0, false)]),
+            run(vec![cluster(5.0, false)]),
+            run(vec![cluster(5.0, false)]),
+        ];
+
+        let truncated =
+            truncate_runs(&runs, Size::zero(), 2, Abs::pt(100.0), false, &ellipsis);
+        assert_eq!(truncated.elements().len(), 2);
+    }
+
+    #[test]
+    fn test_truncate_runs_block_mode_keeps_earlier_lines_that_fit() {
+        // Line 0 fits comfortably; line 1 overflows. Block mode must still
+        // render line 0 and only truncate the overflowing one, rather than
+        // bailing out after line 0 with no indication anything was cut.
+        let ellipsis = EcoString::from("...");
+        let runs = vec![run(vec![cluster(5.0, false)]), run(vec![cluster(200.0, false)])];
+
+        let truncated =
+            truncate_runs(&runs, Size::zero(), usize::MAX, Abs::pt(100.0), false, &ellipsis);
+        assert_eq!(truncated.elements().len(), 2);
+    }
+
+    #[test]
+    fn test_truncate_runs_per_line_mode_truncates_every_overflowing_line() {
+        let ellipsis = EcoString::from("...");
+        let runs =
+            vec![run(vec![cluster(200.0, false)]), run(vec![cluster(200.0, false)])];
+
+        let truncated =
+            truncate_runs(&runs, Size::zero(), usize::MAX, Abs::pt(100.0), true, &ellipsis);
+        assert_eq!(truncated.elements().len(), 2);
+    }
+
+    fn rect(size: Size) -> Element {
+        Element::Shape(Geometry::Rect(size).stroked(Stroke::default()))
+    }
+
+    #[test]
+    fn test_clip_frame_sizes_result_to_clip_size() {
+        let mut frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(100.0)));
+        frame.push(Point::zero(), rect(Size::new(Abs::pt(100.0), Abs::pt(100.0))));
+
+        let clip_size = Size::new(Abs::pt(50.0), Abs::pt(50.0));
+        let clipped = clip_frame(frame, clip_size);
+        assert_eq!(clipped.size(), clip_size);
+    }
+
+    #[test]
+    fn test_clip_frame_drops_elements_overflowing_the_clip_rect() {
+        // The node's own doc example: a 100x100 rect clipped to 50x50 must
+        // not survive, even though its origin is at (0, 0).
+        let mut frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(100.0)));
+        frame.push(Point::zero(), rect(Size::new(Abs::pt(100.0), Abs::pt(100.0))));
+
+        let clipped = clip_frame(frame, Size::new(Abs::pt(50.0), Abs::pt(50.0)));
+        assert!(clipped.elements().is_empty());
+    }
+
+    #[test]
+    fn test_clip_frame_keeps_elements_that_fit() {
+        let mut frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(100.0)));
+        frame.push(Point::zero(), rect(Size::new(Abs::pt(20.0), Abs::pt(20.0))));
+
+        let clipped = clip_frame(frame, Size::new(Abs::pt(50.0), Abs::pt(50.0)));
+        assert_eq!(clipped.elements().len(), 1);
+    }
+}
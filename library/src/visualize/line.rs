@@ -1,29 +1,35 @@
 use crate::prelude::*;
 
 /// # Line
-/// A line from one point to another.
+/// A line from one point to another, or a connected poly-line through
+/// several points.
 ///
 /// ## Example
 /// ```
 /// #set page(height: 100pt)
 /// #line(end: (50%, 50%))
+/// #line(points: ((0pt, 0pt), (30pt, 0pt), (30pt, 30pt)))
 /// ```
 ///
 /// ## Parameters
 /// - start: Axes<Rel<Length>> (named)
-///   The start point of the line.
+///   The start point of the line. Mutually exclusive with `points`.
 ///   Must be an array of exactly two relative lengths.
 ///
 /// - end: Axes<Rel<Length>> (named)
-///   The end point of the line.
+///   The end point of the line. Mutually exclusive with `points`.
 ///   Must be an array of exactly two relative lengths.
 ///
 /// - length: Rel<Length> (named)
-///   The line's length. Mutually exclusive with `end`.
+///   The line's length. Mutually exclusive with `end` and `points`.
 ///
 /// - angle: Angle (named)
 ///   The angle at which the line points away from the origin. Mutually
-///   exclusive with `end`.
+///   exclusive with `end` and `points`.
+///
+/// - points: Array<Axes<Rel<Length>>> (named)
+///   An ordered array of at least two points to connect into a poly-line.
+///   Mutually exclusive with `start`, `end`, `length` and `angle`.
 ///
 /// ## Category
 /// visualize
@@ -31,10 +37,9 @@ use crate::prelude::*;
 #[capable(Layout, Inline)]
 #[derive(Debug, Hash)]
 pub struct LineNode {
-    /// Where the line starts.
-    pub start: Axes<Rel<Length>>,
-    /// The offset from `start` where the line ends.
-    pub delta: Axes<Rel<Length>>,
+    /// The points the line passes through, in order. Has at least two
+    /// elements; for the common two-point case, this is `[start, end]`.
+    pub points: Vec<Axes<Rel<Length>>>,
 }
 
 #[node]
@@ -47,32 +52,62 @@ impl LineNode {
     ///   to `{1pt}`.
     /// - A stroke combined from color and thickness using the `+` operator as
     ///   in `{2pt + red}`.
+    /// - A dictionary with any of the keys `paint`, `thickness`, `dash`,
+    ///   `cap` and `join`, letting the others be inherited. `dash` accepts
+    ///   either a preset name (`{"solid"}`, `{"dashed"}`, `{"dotted"}`) or an
+    ///   explicit array of on/off lengths. `cap` accepts `{"butt"}`,
+    ///   `{"round"}` or `{"square"}`. `join` accepts `{"miter"}`,
+    ///   `{"round"}` or `{"bevel"}`.
     ///
     /// # Example
     /// ```
     /// #line(length: 100%, stroke: 2pt + red)
+    /// #line(
+    ///   length: 100%,
+    ///   stroke: (paint: red, thickness: 2pt, dash: "dashed", cap: "round"),
+    /// )
     /// ```
     #[property(resolve, fold)]
     pub const STROKE: PartialStroke = PartialStroke::default();
 
+    /// A marker (e.g. an arrowhead) drawn at the line's first point.
+    #[property(resolve)]
+    pub const MARKER_START: Option<Marker> = None;
+
+    /// A marker (e.g. an arrowhead) drawn at the line's last point.
+    #[property(resolve)]
+    pub const MARKER_END: Option<Marker> = None;
+
     fn construct(_: &Vm, args: &mut Args) -> SourceResult<Content> {
-        let start = args.named("start")?.unwrap_or_default();
+        let points = if let Some(points) =
+            args.named::<Vec<Axes<Rel<Length>>>>("points")?
+        {
+            if points.len() < 2 {
+                bail!(args.span, "line must pass through at least two points");
+            }
+            points
+        } else {
+            let start: Axes<Rel<Length>> = args.named("start")?.unwrap_or_default();
 
-        let delta = match args.named::<Axes<Rel<Length>>>("end")? {
-            Some(end) => end.zip(start).map(|(to, from)| to - from),
-            None => {
-                let length =
-                    args.named::<Rel<Length>>("length")?.unwrap_or(Abs::cm(1.0).into());
+            let end = match args.named::<Axes<Rel<Length>>>("end")? {
+                Some(end) => end,
+                None => {
+                    let length = args
+                        .named::<Rel<Length>>("length")?
+                        .unwrap_or(Abs::cm(1.0).into());
 
-                let angle = args.named::<Angle>("angle")?.unwrap_or_default();
-                let x = angle.cos() * length;
-                let y = angle.sin() * length;
+                    let angle = args.named::<Angle>("angle")?.unwrap_or_default();
+                    let x = angle.cos() * length;
+                    let y = angle.sin() * length;
 
-                Axes::new(x, y)
-            }
+                    start.zip(Axes::new(x, y)).map(|(s, d)| s + d)
+                }
+            };
+
+            vec![start, end]
         };
 
-        Ok(Self { start, delta }.pack())
+        Ok(Self { points }.pack())
     }
 }
 
@@ -84,27 +119,228 @@ impl Layout for LineNode {
         regions: Regions,
     ) -> SourceResult<Fragment> {
         let stroke = styles.get(Self::STROKE).unwrap_or_default();
+        let marker_start = styles.get(Self::MARKER_START);
+        let marker_end = styles.get(Self::MARKER_END);
 
-        let origin = self
-            .start
-            .resolve(styles)
-            .zip(regions.base)
-            .map(|(l, b)| l.relative_to(b));
-
-        let delta = self
-            .delta
-            .resolve(styles)
-            .zip(regions.base)
-            .map(|(l, b)| l.relative_to(b));
+        let resolved: Vec<Point> = self
+            .points
+            .iter()
+            .map(|point| {
+                point
+                    .resolve(styles)
+                    .zip(regions.base)
+                    .map(|(l, b)| l.relative_to(b))
+                    .to_point()
+            })
+            .collect();
 
         let target = regions.expand.select(regions.first, Size::zero());
-
         let mut frame = Frame::new(target);
-        let shape = Geometry::Line(delta.to_point()).stroked(stroke);
-        frame.push(origin.to_point(), Element::Shape(shape));
+
+        for segment in resolved.windows(2) {
+            let [from, to] = [segment[0], segment[1]];
+            let shape = Geometry::Line(to - from).stroked(stroke);
+            frame.push(from, Element::Shape(shape));
+        }
+
+        if let (Some(marker), Some((point, dir))) =
+            (marker_start, marker_start_dir(&resolved))
+        {
+            push_marker(&mut frame, point, dir, marker, stroke);
+        }
+
+        if let (Some(marker), Some((point, dir))) = (marker_end, marker_end_dir(&resolved)) {
+            push_marker(&mut frame, point, dir, marker, stroke);
+        }
 
         Ok(Fragment::frame(frame))
     }
 }
 
-impl Inline for LineNode {}
\ No newline at end of file
+impl Inline for LineNode {}
+
+/// A marker (e.g. an arrowhead) drawn at an endpoint of a line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Marker {
+    /// The marker's shape.
+    pub shape: MarkerShape,
+    /// The marker's size: an arrow's wing length, half a bar's length, or a
+    /// dot's diameter.
+    pub size: Length,
+}
+
+/// The shape a [`Marker`] takes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MarkerShape {
+    /// No marker at all.
+    None,
+    /// An open arrowhead, like `->`.
+    Arrow,
+    /// A short perpendicular bar, like `|-`.
+    Bar,
+    /// A filled dot.
+    Dot,
+}
+
+/// The point and back-towards-the-interior direction for a marker at the
+/// line's first point, if there are at least two resolved points to derive a
+/// direction from.
+fn marker_start_dir(resolved: &[Point]) -> Option<(Point, Point)> {
+    match resolved {
+        [first, second, ..] => Some((*first, *second - *first)),
+        _ => None,
+    }
+}
+
+/// The point and back-towards-the-interior direction for a marker at the
+/// line's last point, if there are at least two resolved points to derive a
+/// direction from.
+fn marker_end_dir(resolved: &[Point]) -> Option<(Point, Point)> {
+    match resolved {
+        [.., second_last, last] => Some((*last, *second_last - *last)),
+        _ => None,
+    }
+}
+
+/// Push the geometry for `marker` at `point`, oriented along `dir` (which
+/// points from `point` back towards the line's interior).
+fn push_marker(
+    frame: &mut Frame,
+    point: Point,
+    dir: Point,
+    marker: Marker,
+    stroke: Stroke,
+) {
+    if marker.shape == MarkerShape::None {
+        return;
+    }
+
+    let size = marker.size.at(stroke.thickness).to_raw();
+
+    if marker.shape == MarkerShape::Dot {
+        // Unlike Bar/Arrow, a dot has no orientation of its own, so it's
+        // centered on `point` and drawn regardless of `dir` (including the
+        // degenerate zero-length case the other shapes bail out on below).
+        let offset = Point::new(Abs::raw(size / 2.0), Abs::raw(size / 2.0));
+        let shape =
+            Geometry::Ellipse(Size::new(Abs::raw(size), Abs::raw(size))).filled(stroke.paint);
+        frame.push(point - offset, Element::Shape(shape));
+        return;
+    }
+
+    let len = (dir.x.to_raw().powi(2) + dir.y.to_raw().powi(2)).sqrt();
+    if len == 0.0 {
+        return;
+    }
+
+    let ux = dir.x.to_raw() / len;
+    let uy = dir.y.to_raw() / len;
+
+    match marker.shape {
+        MarkerShape::None | MarkerShape::Dot => unreachable!(),
+        MarkerShape::Bar => {
+            // Perpendicular to the line's direction.
+            let (px, py) = (-uy, ux);
+            let half = Point::new(Abs::raw(px * size), Abs::raw(py * size));
+            let full = Point::new(Abs::raw(px * size * 2.0), Abs::raw(py * size * 2.0));
+            frame.push(point - half, Element::Shape(Geometry::Line(full).stroked(stroke)));
+        }
+        MarkerShape::Arrow => {
+            // Two short strokes splayed at ~30° off the back direction.
+            for sign in [-1.0, 1.0] {
+                let angle: f64 = std::f64::consts::FRAC_PI_6;
+                let (cos, sin) = (angle.cos(), angle.sin());
+                let rx = ux * cos - sign * uy * sin;
+                let ry = sign * ux * sin + uy * cos;
+                let wing = Point::new(Abs::raw(rx * size), Abs::raw(ry * size));
+                let shape = Geometry::Line(wing).stroked(stroke);
+                frame.push(point, Element::Shape(shape));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_start_dir_points_towards_second_point() {
+        let resolved =
+            vec![Point::new(Abs::pt(0.0), Abs::zero()), Point::new(Abs::pt(10.0), Abs::zero())];
+        let (point, dir) = marker_start_dir(&resolved).unwrap();
+        assert_eq!(point, resolved[0]);
+        assert_eq!(dir, Point::new(Abs::pt(10.0), Abs::zero()));
+    }
+
+    #[test]
+    fn test_marker_end_dir_points_back_towards_second_last_point() {
+        let resolved =
+            vec![Point::new(Abs::pt(0.0), Abs::zero()), Point::new(Abs::pt(10.0), Abs::zero())];
+        let (point, dir) = marker_end_dir(&resolved).unwrap();
+        assert_eq!(point, resolved[1]);
+        // The line travels in +x; the end marker's direction must point back
+        // in -x, towards the line's interior, not onward past the tip.
+        assert_eq!(dir, Point::new(Abs::pt(-10.0), Abs::zero()));
+    }
+
+    #[test]
+    fn test_marker_dirs_none_for_a_single_point() {
+        let resolved = vec![Point::zero()];
+        assert_eq!(marker_start_dir(&resolved), None);
+        assert_eq!(marker_end_dir(&resolved), None);
+    }
+
+    #[test]
+    fn test_push_marker_dot_is_centered_on_point_regardless_of_dir() {
+        let mut frame = Frame::new(Size::zero());
+        let marker = Marker { shape: MarkerShape::Dot, size: Abs::pt(10.0).into() };
+        let stroke = Stroke::default();
+        let point = Point::new(Abs::pt(3.0), Abs::pt(4.0));
+
+        // A dot has no orientation, so an arbitrary, non-axis-aligned `dir`
+        // must not affect where or how it's drawn.
+        let dir = Point::new(Abs::pt(1.0), Abs::pt(1.0));
+        push_marker(&mut frame, point, dir, marker, stroke);
+
+        let mut found = false;
+        for (pos, element) in frame.elements() {
+            let Element::Shape(shape) = element else { continue };
+            let Geometry::Ellipse(size) = shape.geometry else { continue };
+            assert_eq!(size, Size::new(Abs::pt(10.0), Abs::pt(10.0)));
+            assert_eq!(*pos, point - Point::new(Abs::pt(5.0), Abs::pt(5.0)));
+            found = true;
+        }
+        assert!(found, "dot was not pushed");
+    }
+
+    #[test]
+    fn test_push_marker_dot_is_drawn_even_when_dir_is_zero() {
+        let mut frame = Frame::new(Size::zero());
+        let marker = Marker { shape: MarkerShape::Dot, size: Abs::pt(10.0).into() };
+        let stroke = Stroke::default();
+
+        push_marker(&mut frame, Point::zero(), Point::zero(), marker, stroke);
+
+        assert_eq!(frame.elements().len(), 1);
+    }
+
+    #[test]
+    fn test_push_marker_arrow_wings_splay_back_towards_the_interior() {
+        let mut frame = Frame::new(Size::zero());
+        let marker = Marker { shape: MarkerShape::Arrow, size: Abs::pt(10.0).into() };
+        let stroke = Stroke::default();
+
+        // Line travels in +x, so the back-towards-interior direction is -x.
+        let dir = Point::new(Abs::pt(-1.0), Abs::zero());
+        push_marker(&mut frame, Point::zero(), dir, marker, stroke);
+
+        for (_, element) in frame.elements() {
+            let Element::Shape(shape) = element else { continue };
+            let Geometry::Line(wing) = shape.geometry else { continue };
+            // Each wing should splay backward (negative x), never forward
+            // past the tip (positive x).
+            assert!(wing.x <= Abs::zero());
+        }
+    }
+}
\ No newline at end of file
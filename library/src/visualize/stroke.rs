@@ -0,0 +1,294 @@
+use crate::prelude::*;
+
+/// How to stroke a shape, fully resolved and ready to be painted.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct Stroke {
+    /// The stroke's paint.
+    pub paint: Paint,
+    /// The stroke's thickness.
+    pub thickness: Abs,
+    /// The dash pattern to paint the stroke with, if any. `None` means a
+    /// solid line.
+    pub dash: Option<DashPattern<Abs>>,
+    /// How the ends of the stroke are rendered.
+    pub cap: LineCap,
+    /// How segments of the stroke are joined where they meet.
+    pub join: LineJoin,
+}
+
+impl Default for Stroke {
+    fn default() -> Self {
+        Self {
+            paint: Color::BLACK.into(),
+            thickness: Abs::pt(1.0),
+            dash: None,
+            cap: LineCap::Butt,
+            join: LineJoin::miter(),
+        }
+    }
+}
+
+/// A stroke with every field optionally specified, as produced by
+/// user-facing arguments like `#line(stroke: ..)`. Unset fields fall back to
+/// whatever an outer stroke (or the default) provides once folded/resolved.
+#[derive(Debug, Default, Clone, PartialEq, Hash)]
+pub struct PartialStroke<T = Length> {
+    /// The stroke's paint.
+    pub paint: Smart<Paint>,
+    /// The stroke's thickness.
+    pub thickness: Smart<T>,
+    /// The dash pattern to paint the stroke with. `Smart::Custom(None)` means
+    /// an explicit solid line, overriding any inherited dash pattern.
+    pub dash: Smart<Option<DashPattern<T>>>,
+    /// How the ends of the stroke are rendered.
+    pub cap: Smart<LineCap>,
+    /// How segments of the stroke are joined where they meet.
+    pub join: Smart<LineJoin>,
+}
+
+impl<T> PartialStroke<T> {
+    /// Whether nothing at all was specified.
+    fn is_auto(&self) -> bool {
+        self.paint.is_auto()
+            && self.thickness.is_auto()
+            && self.dash.is_auto()
+            && self.cap.is_auto()
+            && self.join.is_auto()
+    }
+}
+
+impl Resolve for PartialStroke {
+    type Output = Option<Stroke>;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        if self.is_auto() {
+            return None;
+        }
+
+        let default = Stroke::default();
+        Some(Stroke {
+            paint: self.paint.unwrap_or(default.paint),
+            thickness: self
+                .thickness
+                .map(|t| t.resolve(styles))
+                .unwrap_or(default.thickness),
+            dash: match self.dash {
+                Smart::Auto => default.dash,
+                Smart::Custom(dash) => {
+                    dash.map(|pattern| pattern.resolve(styles))
+                }
+            },
+            cap: self.cap.unwrap_or(default.cap),
+            join: self.join.unwrap_or(default.join),
+        })
+    }
+}
+
+impl<T: Fold> Fold for PartialStroke<T> {
+    type Output = Self;
+
+    fn fold(self, outer: Self::Output) -> Self::Output {
+        Self {
+            paint: self.paint.or(outer.paint),
+            thickness: self.thickness.or(outer.thickness),
+            dash: self.dash.or(outer.dash),
+            cap: self.cap.or(outer.cap),
+            join: self.join.or(outer.join),
+        }
+    }
+}
+
+/// A dash pattern: alternating on/off lengths, plus a phase offset into the
+/// pattern at which the stroke starts.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct DashPattern<T = Length> {
+    /// The alternating lengths, starting with an "on" segment.
+    pub array: Vec<T>,
+    /// The offset into the pattern at which painting starts.
+    pub phase: T,
+}
+
+impl<T: Default> Default for DashPattern<T> {
+    fn default() -> Self {
+        Self { array: vec![], phase: T::default() }
+    }
+}
+
+impl DashPattern<Length> {
+    /// Look up a named preset (`"solid"`, `"dashed"`, `"dotted"`). Returns
+    /// `Some(None)` for `"solid"` (no dash pattern at all) and `None` if
+    /// `name` isn't a known preset.
+    pub fn preset(name: &str) -> Option<Option<Self>> {
+        match name {
+            "solid" => Some(None),
+            "dashed" => Some(Some(Self {
+                array: vec![Length::em(1.0), Length::em(0.5)],
+                phase: Length::zero(),
+            })),
+            "dotted" => Some(Some(Self {
+                array: vec![Length::em(0.2), Length::em(0.4)],
+                phase: Length::zero(),
+            })),
+            _ => None,
+        }
+    }
+}
+
+impl Resolve for DashPattern<Length> {
+    type Output = DashPattern<Abs>;
+
+    fn resolve(self, styles: StyleChain) -> Self::Output {
+        DashPattern {
+            array: self.array.into_iter().map(|l| l.resolve(styles)).collect(),
+            phase: self.phase.resolve(styles),
+        }
+    }
+}
+
+castable! {
+    Option<DashPattern<Length>>,
+    Value::Str(string) => DashPattern::preset(&string).ok_or_else(|| {
+        format!(
+            "expected \"solid\", \"dashed\", \"dotted\" or an array of lengths, found \"{string}\"",
+        )
+    })?,
+    Value::Array(array) => Some(DashPattern {
+        array: array.into_iter().map(Length::cast).collect::<StrResult<_>>()?,
+        phase: Length::zero(),
+    }),
+}
+
+/// How the ends of a stroke are rendered.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LineCap {
+    /// The stroke ends exactly at its endpoint.
+    Butt,
+    /// The stroke is extended by a half circle past its endpoint.
+    Round,
+    /// The stroke is extended by a square with half the stroke's thickness
+    /// past its endpoint.
+    Square,
+}
+
+impl Default for LineCap {
+    fn default() -> Self {
+        Self::Butt
+    }
+}
+
+castable! {
+    LineCap,
+    Value::Str(string) => match string.as_str() {
+        "butt" => Self::Butt,
+        "round" => Self::Round,
+        "square" => Self::Square,
+        _ => Err(r#"expected "butt", "round" or "square""#)?,
+    },
+}
+
+/// How segments of a stroke are joined where they meet.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub enum LineJoin {
+    /// Segments are joined with a sharp corner, unless the angle is sharper
+    /// than `miter_limit`, in which case a bevel join is used instead.
+    Miter(Scalar),
+    /// Segments are joined with a rounded corner.
+    Round,
+    /// Segments are joined with a flat bevel that connects the endpoints of
+    /// the two segments' outlines.
+    Bevel,
+}
+
+impl LineJoin {
+    /// The default miter limit, as used by SVG and PostScript.
+    pub const DEFAULT_MITER_LIMIT: f64 = 4.0;
+
+    /// A miter join with the default miter limit.
+    pub fn miter() -> Self {
+        Self::Miter(Scalar(Self::DEFAULT_MITER_LIMIT))
+    }
+}
+
+impl Default for LineJoin {
+    fn default() -> Self {
+        Self::miter()
+    }
+}
+
+castable! {
+    LineJoin,
+    Value::Str(string) => match string.as_str() {
+        "miter" => Self::miter(),
+        "round" => Self::Round,
+        "bevel" => Self::Bevel,
+        _ => Err(r#"expected "miter", "round" or "bevel""#)?,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dash_pattern_preset_solid_is_no_pattern() {
+        assert_eq!(DashPattern::<Length>::preset("solid"), Some(None));
+    }
+
+    #[test]
+    fn test_dash_pattern_preset_dashed_and_dotted_are_distinct() {
+        let dashed = DashPattern::<Length>::preset("dashed").unwrap().unwrap();
+        let dotted = DashPattern::<Length>::preset("dotted").unwrap().unwrap();
+        assert_ne!(dashed.array, dotted.array);
+    }
+
+    #[test]
+    fn test_dash_pattern_preset_unknown_name_is_none() {
+        assert_eq!(DashPattern::<Length>::preset("wavy"), None);
+    }
+
+    #[test]
+    fn test_partial_stroke_fold_prefers_own_fields_over_outer() {
+        let inner = PartialStroke {
+            thickness: Smart::Custom(Length::pt(2.0)),
+            ..Default::default()
+        };
+        let outer = PartialStroke {
+            thickness: Smart::Custom(Length::pt(5.0)),
+            cap: Smart::Custom(LineCap::Round),
+            ..Default::default()
+        };
+
+        let folded = inner.fold(outer);
+        assert_eq!(folded.thickness, Smart::Custom(Length::pt(2.0)));
+    }
+
+    #[test]
+    fn test_partial_stroke_fold_falls_back_to_outer_for_unset_fields() {
+        let inner = PartialStroke { thickness: Smart::Custom(Length::pt(2.0)), ..Default::default() };
+        let outer = PartialStroke {
+            cap: Smart::Custom(LineCap::Round),
+            join: Smart::Custom(LineJoin::Bevel),
+            ..Default::default()
+        };
+
+        let folded = inner.fold(outer);
+        assert_eq!(folded.cap, Smart::Custom(LineCap::Round));
+        assert_eq!(folded.join, Smart::Custom(LineJoin::Bevel));
+    }
+
+    #[test]
+    fn test_partial_stroke_is_auto_true_only_when_nothing_set() {
+        assert!(PartialStroke::<Length>::default().is_auto());
+
+        let partial = PartialStroke {
+            cap: Smart::Custom(LineCap::Round),
+            ..Default::default()
+        };
+        assert!(!partial.is_auto());
+    }
+
+    #[test]
+    fn test_line_join_default_is_miter_with_default_limit() {
+        assert_eq!(LineJoin::default(), LineJoin::Miter(Scalar(LineJoin::DEFAULT_MITER_LIMIT)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Add;
+
 use super::{StyleChain, StyleVec, StyleVecBuilder};
 
 /// A wrapper around a [`StyleVecBuilder`] that allows to collapse items.
@@ -5,8 +7,9 @@ pub struct CollapsingBuilder<'a, T> {
     /// The internal builder.
     builder: StyleVecBuilder<'a, T>,
     /// Staged weak and ignorant items that we can't yet commit to the builder.
-    /// The option is `Some(_)` for weak items and `None` for ignorant items.
-    staged: Vec<(T, StyleChain<'a>, Option<u8>)>,
+    /// The option is `Some((weakness, kind))` for weak items and `None` for
+    /// ignorant items.
+    staged: Vec<(T, StyleChain<'a>, Option<(u8, Weak<T>)>)>,
     /// What the last non-ignorant item was.
     last: Last,
 }
@@ -19,6 +22,20 @@ enum Last {
     Supportive,
 }
 
+/// What kind of competition a staged weak item participates in.
+enum Weak<T> {
+    /// Competes "strongest wins" against other `Plain` candidates of any
+    /// weakness (see [`CollapsingBuilder::weak`]).
+    Plain,
+    /// Collapses with other `Collapse` candidates of the same weakness using
+    /// the CSS margin-collapsing rule (see
+    /// [`CollapsingBuilder::weak_collapse`]). Tracks the running
+    /// max-positive and min-negative candidates seen so far in this gap, so
+    /// that folding in one more candidate never has to re-derive the split
+    /// from an already-folded value.
+    Collapse { pos: Option<T>, neg: Option<T> },
+}
+
 impl<'a, T> CollapsingBuilder<'a, T> {
     /// Create a new style-vec builder.
     pub fn new() -> Self {
@@ -51,20 +68,95 @@ impl<'a, T> CollapsingBuilder<'a, T> {
 
         if self.last == Last::Weak {
             if let Some(i) =
-                self.staged.iter().position(|(prev_item, _, prev_weakness)| {
-                    prev_weakness.map_or(false, |prev_weakness| {
-                        weakness < prev_weakness
-                            || (weakness == prev_weakness && item > *prev_item)
+                self.staged.iter().position(|(prev_item, _, meta)| {
+                    meta.as_ref().map_or(false, |(prev_weakness, kind)| {
+                        matches!(kind, Weak::Plain)
+                            && (weakness < *prev_weakness
+                                || (weakness == *prev_weakness && item > *prev_item))
                     })
                 })
             {
+                // This item beats a same-flavor competitor; replace it.
                 self.staged.remove(i);
-            } else {
+            } else if self.staged.iter().any(|(prev_item, _, meta)| {
+                meta.as_ref().map_or(false, |(prev_weakness, kind)| {
+                    matches!(kind, Weak::Plain)
+                        && (*prev_weakness < weakness
+                            || (*prev_weakness == weakness && *prev_item >= item))
+                })
+            }) {
+                // A same-flavor competitor beats this item; drop it. Items
+                // staged by `weak_collapse` don't compete with this flavor at
+                // all, so their presence alone must not cause a drop.
                 return;
             }
         }
 
-        self.staged.push((item, styles, Some(weakness)));
+        self.staged.push((item, styles, Some((weakness, Weak::Plain))));
+        self.last = Last::Weak;
+    }
+
+    /// Like [`Self::weak`], but instead of discarding all but the strongest
+    /// candidate, equally-weighted adjacent candidates are folded into a
+    /// single value using the CSS margin-collapsing rule: the collapsed
+    /// value is `max(all positive candidates) + min(all negative
+    /// candidates)`. As with `weak`, a candidate of a smaller `weakness`
+    /// displaces all weaker ones, and a weaker candidate arriving after a
+    /// stronger one is dropped.
+    pub fn weak_collapse(&mut self, item: T, styles: StyleChain<'a>, weakness: u8)
+    where
+        T: PartialOrd + Add<Output = T> + Default + Copy,
+    {
+        if self.last == Last::Destructive {
+            return;
+        }
+
+        if self.last == Last::Weak {
+            if let Some(i) = self.staged.iter().position(|(_, _, meta)| {
+                meta.as_ref().map_or(false, |(prev_weakness, kind)| {
+                    matches!(kind, Weak::Collapse { .. }) && weakness < *prev_weakness
+                })
+            }) {
+                self.staged.remove(i);
+            } else if let Some(i) = self.staged.iter().position(|(_, _, meta)| {
+                meta.as_ref().map_or(false, |(prev_weakness, kind)| {
+                    matches!(kind, Weak::Collapse { .. }) && weakness == *prev_weakness
+                })
+            }) {
+                let (_, prev_styles, prev_meta) = self.staged.remove(i);
+                let (pos, neg) = match prev_meta {
+                    Some((_, Weak::Collapse { pos, neg })) => (pos, neg),
+                    _ => (None, None),
+                };
+
+                // Accumulate into the running max-positive/min-negative
+                // split rather than re-folding the prior *folded* value as
+                // if it were itself a fresh candidate, which would
+                // double-count it on the wrong side once the running fold
+                // crosses sign.
+                let (pos, neg) = accumulate(pos, neg, item);
+                self.staged.push((
+                    combine(pos, neg),
+                    prev_styles,
+                    Some((weakness, Weak::Collapse { pos, neg })),
+                ));
+                self.last = Last::Weak;
+                return;
+            } else if self.staged.iter().any(|(_, _, meta)| {
+                meta.as_ref().map_or(false, |(prev_weakness, kind)| {
+                    matches!(kind, Weak::Collapse { .. }) && weakness > *prev_weakness
+                })
+            }) {
+                return;
+            }
+        }
+
+        let (pos, neg) = accumulate(None, None, item);
+        self.staged.push((
+            combine(pos, neg),
+            styles,
+            Some((weakness, Weak::Collapse { pos, neg })),
+        ));
         self.last = Last::Weak;
     }
 
@@ -115,6 +207,39 @@ impl<'a, T> Default for CollapsingBuilder<'a, T> {
     }
 }
 
+/// Merge one more candidate into a running max-positive/min-negative split,
+/// as used by the CSS margin-collapsing rule. Keeping the split (rather than
+/// a single folded value) lets further candidates be merged in without ever
+/// re-deriving the split from an already-folded result.
+fn accumulate<T>(pos: Option<T>, neg: Option<T>, item: T) -> (Option<T>, Option<T>)
+where
+    T: PartialOrd + Default + Copy,
+{
+    let zero = T::default();
+    if item >= zero {
+        (Some(pos.map_or(item, |prev| if item > prev { item } else { prev })), neg)
+    } else {
+        (pos, Some(neg.map_or(item, |prev| if item < prev { item } else { prev })))
+    }
+}
+
+/// Combine a max-positive/min-negative split into the CSS margin-collapsing
+/// result: `max(all positive values) + min(all negative values)`. If only
+/// positive (or only negative) values are present, the other side
+/// contributes zero.
+fn combine<T>(pos: Option<T>, neg: Option<T>) -> T
+where
+    T: Add<Output = T> + Default,
+{
+    let zero = T::default();
+    match (pos, neg) {
+        (Some(pos), Some(neg)) => pos + neg,
+        (Some(pos), None) => pos,
+        (None, Some(neg)) => neg,
+        (None, None) => zero,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +299,78 @@ mod tests {
         builder.supportive(node(), styles);
         test(builder, &[node(), FlowChild::Colbreak, node()]);
     }
+
+    #[test]
+    fn test_collapsing_weak_collapse() {
+        // Two positive margins collapse to the larger one.
+        let mut builder = CollapsingBuilder::new();
+        let styles = StyleChain::default();
+        builder.supportive(0i64, styles);
+        builder.weak_collapse(10, styles, 0);
+        builder.weak_collapse(6, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, 10, 0]);
+
+        // A positive and a negative margin sum.
+        let mut builder = CollapsingBuilder::new();
+        builder.supportive(0, styles);
+        builder.weak_collapse(10, styles, 0);
+        builder.weak_collapse(-4, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, 6, 0]);
+
+        // Two negative margins collapse to the smaller (more negative) one.
+        let mut builder = CollapsingBuilder::new();
+        builder.supportive(0, styles);
+        builder.weak_collapse(-4, styles, 0);
+        builder.weak_collapse(-8, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, -8, 0]);
+
+        // A stronger candidate still displaces a weaker one entirely.
+        let mut builder = CollapsingBuilder::new();
+        builder.supportive(0, styles);
+        builder.weak_collapse(10, styles, 1);
+        builder.weak_collapse(6, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, 6, 0]);
+    }
+
+    #[test]
+    fn test_collapsing_weak_collapse_three_candidates_same_weakness() {
+        // max(10) + min(-4, -8) = 2. Folding this pairwise against the
+        // already-folded running value (fold(10,-4)=6, then fold(6,-8)=-2)
+        // would give the wrong, sign-flipped answer; the max-positive and
+        // min-negative must each be tracked across all three candidates.
+        let mut builder = CollapsingBuilder::new();
+        let styles = StyleChain::default();
+        builder.supportive(0i64, styles);
+        builder.weak_collapse(10, styles, 0);
+        builder.weak_collapse(-4, styles, 0);
+        builder.weak_collapse(-8, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, 2, 0]);
+    }
+
+    #[test]
+    fn test_collapsing_weak_mixed_flavor() {
+        // A `weak_collapse` candidate must not cause an unrelated `weak`
+        // candidate in the same gap to be dropped, even though both set
+        // `last` to `Weak`: they don't compete with each other.
+        let mut builder = CollapsingBuilder::new();
+        let styles = StyleChain::default();
+        builder.supportive(0i64, styles);
+        builder.weak_collapse(10, styles, 0);
+        builder.weak(99, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, 10, 99, 0]);
+
+        // And the reverse order.
+        let mut builder = CollapsingBuilder::new();
+        builder.supportive(0, styles);
+        builder.weak(99, styles, 0);
+        builder.weak_collapse(10, styles, 0);
+        builder.supportive(0, styles);
+        test(builder, &[0, 99, 10, 0]);
+    }
 }
\ No newline at end of file